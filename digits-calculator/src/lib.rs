@@ -86,6 +86,59 @@ fn divide(a: f64, b: f64) -> PyResult<f64> {
     Ok(a / b)
 }
 
+/// Divides two numbers following IEEE-754 semantics instead of raising.
+///
+/// Unlike [`divide`], this never raises `ZeroDivisionError`: division by
+/// zero yields `+inf`/`-inf` (the sign is the product of the signs of `a`
+/// and the zero, so `1.0 / -0.0` is `-inf` and `-1.0 / -0.0` is `+inf`)
+/// and `0.0 / 0.0` yields `NaN`, matching the rules CPython's
+/// `math` module documents for IEEE 754 floating point.
+///
+/// # Arguments
+/// * `a` - Dividend (numerator)
+/// * `b` - Divisor (denominator)
+///
+/// # Returns
+/// * `PyResult<f64>` - The result of division a / b, possibly inf or NaN
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.divide_ieee(1.0, 0.0)   # inf
+/// digits_calculator.divide_ieee(1.0, -0.0)  # -inf
+/// digits_calculator.divide_ieee(0.0, 0.0)   # nan
+/// ```
+#[pyfunction]
+fn divide_ieee(a: f64, b: f64) -> PyResult<f64> {
+    // Rust's `/` on f64 already follows IEEE 754: a/±0.0 yields an
+    // infinity signed by both operands' signs and 0.0/0.0 yields NaN, with no need to
+    // special-case the zero divisor ourselves.
+    Ok(a / b)
+}
+
+/// Calculates the square root following IEEE-754 semantics instead of raising.
+///
+/// Unlike [`safe_sqrt`], this never raises `ValueError`: the square root
+/// of a negative number is `NaN`, and `sqrt(-0.0)` is `-0.0`.
+///
+/// # Arguments
+/// * `x` - The number to get the square root of
+///
+/// # Returns
+/// * `PyResult<f64>` - The square root of x, or NaN if x is negative
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.sqrt_ieee(16.0)  # 4.0
+/// digits_calculator.sqrt_ieee(-9.0)  # nan
+/// digits_calculator.sqrt_ieee(-0.0)  # -0.0
+/// ```
+#[pyfunction]
+fn sqrt_ieee(x: f64) -> PyResult<f64> {
+    Ok(x.sqrt())
+}
+
 /// Calculates the square root and handles negative numbers.
 ///
 /// Demonstrates custom error handling for invalid inputs.
@@ -171,6 +224,567 @@ fn factorial(n: i32) -> PyResult<u64> {
     Ok(result)
 }
 
+/// A minimal arbitrary-precision unsigned integer.
+///
+/// Stores the value as little-endian limbs in base 1_000_000_000, which
+/// keeps formatting trivial (each limb but the last is zero-padded to
+/// 9 digits) while avoiding per-digit carry arithmetic.
+#[derive(Clone)]
+struct BigUint {
+    /// Little-endian base-1_000_000_000 limbs.
+    limbs: Vec<u32>,
+}
+
+const BIG_UINT_BASE: u64 = 1_000_000_000;
+
+impl BigUint {
+    fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    /// Multiplies `self` in place by a small scalar `k`.
+    fn mul_small(&mut self, k: u64) {
+        let mut carry: u64 = 0;
+        for limb in self.limbs.iter_mut() {
+            let cur = *limb as u64 * k + carry;
+            *limb = (cur % BIG_UINT_BASE) as u32;
+            carry = cur / BIG_UINT_BASE;
+        }
+        while carry > 0 {
+            self.limbs.push((carry % BIG_UINT_BASE) as u32);
+            carry /= BIG_UINT_BASE;
+        }
+    }
+
+    /// Formats the value as a plain decimal string.
+    fn to_decimal_string(&self) -> String {
+        let mut limbs = self.limbs.iter().rev();
+        let mut s = limbs.next().map_or("0".to_string(), |top| top.to_string());
+        for limb in limbs {
+            s.push_str(&format!("{:09}", limb));
+        }
+        s
+    }
+}
+
+/// Calculates an exact factorial of arbitrary size as a decimal string.
+///
+/// Unlike [`factorial`], which saturates past `20!`, this builds the
+/// exact result using a base-1_000_000_000 big integer, so it stays
+/// correct no matter how large `n` is.
+///
+/// # Arguments
+/// * `n` - The number to calculate factorial for
+///
+/// # Returns
+/// * `PyResult<String>` - The exact factorial of n as a decimal string
+///
+/// # Raises
+/// * `ValueError` - If n is negative
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// result = digits_calculator.factorial_big(30)
+/// # Result: "265252859812191058636308480000000"
+/// ```
+#[pyfunction]
+fn factorial_big(n: i32) -> PyResult<String> {
+    if n < 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Factorial is not defined for negative numbers",
+        ));
+    }
+
+    let mut acc = BigUint::one();
+    for i in 2..=(n as u64) {
+        acc.mul_small(i);
+    }
+
+    Ok(acc.to_decimal_string())
+}
+
+/// Lanczos approximation coefficients for g = 7, as used by most
+/// reference implementations of the gamma function.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Evaluates the gamma function using the Lanczos approximation.
+///
+/// For `x < 0.5` this applies the reflection formula
+/// `Γ(x) = π / (sin(πx)·Γ(1−x))` so the approximation only needs to cover
+/// the right half-plane. The poles at non-positive integers (where
+/// `sin(πx) == 0`) return `NaN`.
+///
+/// # Arguments
+/// * `x` - The argument to evaluate Γ at
+///
+/// # Returns
+/// * `PyResult<f64>` - Γ(x), or NaN at the poles
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.gamma(6.0)  # approx 120.0, since Gamma(n) = (n-1)!
+/// ```
+#[pyfunction]
+fn gamma(x: f64) -> PyResult<f64> {
+    Ok(gamma_impl(x))
+}
+
+fn gamma_impl(x: f64) -> f64 {
+    if x <= 0.0 && x.fract() == 0.0 {
+        return f64::NAN;
+    }
+
+    if x < 0.5 {
+        let sin_term = (std::f64::consts::PI * x).sin();
+        return std::f64::consts::PI / (sin_term * gamma_impl(1.0 - x));
+    }
+
+    let mut a = LANCZOS_COEFFICIENTS[0];
+    for (i, c) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        a += c / (x - 1.0 + i as f64);
+    }
+    let t = x - 1.0 + LANCZOS_G + 0.5;
+    // Evaluate in log-space: `t.powf(x - 0.5)` and `(-t).exp()` each
+    // individually overflow/underflow well before their true product
+    // does, which made gamma() return `inf` as early as x ~ 142 even
+    // though the true value (e.g. Gamma(170) ~ 4.27e304) fits in f64.
+    let ln_result = 0.5 * (2.0 * std::f64::consts::PI).ln() + (x - 0.5) * t.ln() - t + a.ln();
+    ln_result.exp()
+}
+
+/// Generalized factorial for real arguments, via `Γ(x + 1)`.
+///
+/// Extends [`factorial`] to fractional and large arguments, returning
+/// `inf` on overflow per IEEE 754 rather than raising `OverflowError`.
+///
+/// # Arguments
+/// * `x` - The argument to take the factorial of
+///
+/// # Returns
+/// * `PyResult<f64>` - x!, or inf on overflow
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.factorial_real(5.0)  # approx 120.0
+/// digits_calculator.factorial_real(0.5)  # approx 0.8862269...
+/// ```
+#[pyfunction]
+fn factorial_real(x: f64) -> PyResult<f64> {
+    Ok(gamma_impl(x + 1.0))
+}
+
+/// Computes the double factorial `n!! = n·(n-2)·(n-4)·…`.
+///
+/// By convention `0!! == 1` and `(-1)!! == 1`.
+///
+/// # Arguments
+/// * `n` - The number to compute the double factorial for
+///
+/// # Returns
+/// * `PyResult<u64>` - n!!
+///
+/// # Raises
+/// * `ValueError` - If n is less than -1
+/// * `OverflowError` - If the result overflows u64
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.double_factorial(7)  # 105, since 7*5*3*1 = 105
+/// ```
+#[pyfunction]
+fn double_factorial(n: i64) -> PyResult<u64> {
+    if n < -1 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Double factorial is not defined for n < -1",
+        ));
+    }
+    if n <= 0 {
+        return Ok(1);
+    }
+
+    let mut result: u64 = 1;
+    let mut i = n;
+    while i > 0 {
+        result = result.checked_mul(i as u64).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyOverflowError, _>("Double factorial result is too large")
+        })?;
+        i -= 2;
+    }
+
+    Ok(result)
+}
+
+impl BigUint {
+    fn zero() -> Self {
+        BigUint { limbs: vec![0] }
+    }
+
+    /// Adds `other` to `self` in place.
+    fn add_assign(&mut self, other: &BigUint) {
+        let mut carry: u64 = 0;
+        for i in 0..other.limbs.len().max(self.limbs.len()) {
+            let a = *self.limbs.get(i).unwrap_or(&0) as u64;
+            let b = *other.limbs.get(i).unwrap_or(&0) as u64;
+            let cur = a + b + carry;
+            let limb = (cur % BIG_UINT_BASE) as u32;
+            carry = cur / BIG_UINT_BASE;
+            if i < self.limbs.len() {
+                self.limbs[i] = limb;
+            } else {
+                self.limbs.push(limb);
+            }
+        }
+        while carry > 0 {
+            self.limbs.push((carry % BIG_UINT_BASE) as u32);
+            carry /= BIG_UINT_BASE;
+        }
+    }
+}
+
+/// The largest `n` for which `fibonacci(n)` fits in a `u128`.
+const FIBONACCI_MAX_N: u64 = 186;
+
+/// Calculates the nth Fibonacci number (`F(0) = 0`, `F(1) = 1`) iteratively.
+///
+/// Raises `OverflowError` once the result would overflow `u128` (past
+/// `F(186)`); use [`fibonacci_big`] beyond that ceiling.
+///
+/// # Arguments
+/// * `n` - The index into the Fibonacci sequence
+///
+/// # Returns
+/// * `PyResult<u128>` - F(n)
+///
+/// # Raises
+/// * `OverflowError` - If n is greater than 186
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// result = digits_calculator.fibonacci(10)
+/// # Result: 55
+/// ```
+#[pyfunction]
+fn fibonacci(n: u64) -> PyResult<u128> {
+    if n > FIBONACCI_MAX_N {
+        return Err(PyErr::new::<pyo3::exceptions::PyOverflowError, _>(format!(
+            "fibonacci({}) overflows u128; use fibonacci_big instead",
+            n
+        )));
+    }
+
+    let (mut a, mut b): (u128, u128) = (0, 1);
+    for _ in 0..n {
+        // The very last `next` may be F(n+1), which can overflow u128
+        // exactly at n == FIBONACCI_MAX_N; it's discarded once the loop
+        // ends, so wrapping here is harmless.
+        let next = a.wrapping_add(b);
+        a = b;
+        b = next;
+    }
+    Ok(a)
+}
+
+/// Calculates the nth Fibonacci number as an exact decimal string.
+///
+/// Uses the same base-1_000_000_000 big integer as [`factorial_big`], so
+/// there is no ceiling on `n` the way there is for [`fibonacci`].
+///
+/// # Arguments
+/// * `n` - The index into the Fibonacci sequence
+///
+/// # Returns
+/// * `PyResult<String>` - F(n) as a decimal string
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// result = digits_calculator.fibonacci_big(200)
+/// # Result: "280571172992510140037611932413038677189525"
+/// ```
+#[pyfunction]
+fn fibonacci_big(n: u64) -> PyResult<String> {
+    let (mut a, mut b) = (BigUint::zero(), BigUint::one());
+    for _ in 0..n {
+        let mut next = a.clone();
+        next.add_assign(&b);
+        a = b;
+        b = next;
+    }
+    Ok(a.to_decimal_string())
+}
+
+/// A type with an additive identity, mirroring `num_traits::Zero`.
+trait Zero {
+    fn zero() -> Self;
+}
+
+impl Zero for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+impl Zero for i64 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+/// Computes the dot product of two equal-length slices.
+///
+/// The default implementation accumulates naively; [`f64`] overrides it
+/// with Neumaier compensated summation, since that's the only element
+/// type where rounding error actually accumulates.
+trait DotProduct: Copy + std::ops::Add<Output = Self> + std::ops::Mul<Output = Self> + Zero {
+    fn dot(xs: &[Self], ys: &[Self]) -> Self {
+        xs.iter()
+            .zip(ys.iter())
+            .fold(Self::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+}
+
+impl DotProduct for i64 {}
+
+impl DotProduct for f64 {
+    fn dot(xs: &[Self], ys: &[Self]) -> Self {
+        let mut sum = 0.0;
+        let mut c = 0.0;
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            let p = x * y;
+            let t = sum + p;
+            if sum.abs() >= p.abs() {
+                c += (sum - t) + p;
+            } else {
+                c += (p - t) + sum;
+            }
+            sum = t;
+        }
+        sum + c
+    }
+}
+
+/// Multiplies two matrices of any [`DotProduct`] element type.
+fn matrix_multiply_generic<T: DotProduct>(a: &[Vec<T>], b: &[Vec<T>]) -> Result<Vec<Vec<T>>, String> {
+    if a.is_empty() || b.is_empty() {
+        return Err("Matrices cannot be empty".to_string());
+    }
+
+    let rows_a = a.len();
+    let cols_a = a[0].len();
+    let rows_b = b.len();
+    let cols_b = b[0].len();
+
+    for row in a.iter() {
+        if row.len() != cols_a {
+            return Err(
+                "All rows in matrix A must have the same number of columns".to_string(),
+            );
+        }
+    }
+
+    for row in b.iter() {
+        if row.len() != cols_b {
+            return Err(
+                "All rows in matrix B must have the same number of columns".to_string(),
+            );
+        }
+    }
+
+    if cols_a != rows_b {
+        return Err(format!(
+            "Cannot multiply matrices: A is {}x{}, B is {}x{}. Columns of A ({}) must equal rows of B ({})",
+            rows_a, cols_a, rows_b, cols_b, cols_a, rows_b
+        ));
+    }
+
+    let mut result = vec![vec![T::zero(); cols_b]; rows_a];
+    for i in 0..rows_a {
+        let row = &a[i];
+        for j in 0..cols_b {
+            let col: Vec<T> = (0..cols_a).map(|k| b[k][j]).collect();
+            result[i][j] = T::dot(row, &col);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Either an integer or a floating-point matrix, accepted from Python.
+#[derive(FromPyObject)]
+enum MatrixArg {
+    Ints(Vec<Vec<i64>>),
+    Floats(Vec<Vec<f64>>),
+}
+
+impl MatrixArg {
+    fn into_floats(self) -> Vec<Vec<f64>> {
+        match self {
+            MatrixArg::Floats(m) => m,
+            MatrixArg::Ints(m) => m
+                .into_iter()
+                .map(|row| row.into_iter().map(|v| v as f64).collect())
+                .collect(),
+        }
+    }
+}
+
+/// Either an integer or a floating-point matrix, returned to Python.
+enum MatrixResult {
+    Ints(Vec<Vec<i64>>),
+    Floats(Vec<Vec<f64>>),
+}
+
+impl IntoPy<PyObject> for MatrixResult {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            MatrixResult::Ints(m) => m.into_py(py),
+            MatrixResult::Floats(m) => m.into_py(py),
+        }
+    }
+}
+
+/// Multiplies two matrices, dispatching to the integer or floating-point
+/// monomorphization of [`matrix_multiply_generic`] depending on input.
+///
+/// Multiplying two integer matrices stays exact (no float rounding);
+/// mixing an integer matrix with a float matrix promotes both to `f64`.
+///
+/// # Arguments
+/// * `a` - First matrix, either `list[list[int]]` or `list[list[float]]`
+/// * `b` - Second matrix, same shape rules as `a`
+///
+/// # Returns
+/// * `PyResult` - The resulting matrix, same element kind as the inputs
+///   (or `float` if the inputs were mixed)
+///
+/// # Raises
+/// * `ValueError` - If the matrix dimensions are incompatible
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// digits_calculator.matrix_multiply([[1, 2]], [[3], [4]])      # [[11]] (ints)
+/// digits_calculator.matrix_multiply([[1.0, 2.0]], [[3.0], [4.0]])  # [[11.0]] (floats)
+/// ```
+#[pyfunction]
+fn matrix_multiply(a: MatrixArg, b: MatrixArg) -> PyResult<MatrixResult> {
+    let result = match (a, b) {
+        (MatrixArg::Ints(a), MatrixArg::Ints(b)) => {
+            matrix_multiply_generic(&a, &b).map(MatrixResult::Ints)
+        }
+        (a, b) => {
+            let a = a.into_floats();
+            let b = b.into_floats();
+            matrix_multiply_generic(&a, &b).map(MatrixResult::Floats)
+        }
+    };
+    result.map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+}
+
+/// A closed interval `[lo, hi]` that rigorously encloses a real value.
+///
+/// Arithmetic on `Interval` propagates a guaranteed enclosure rather than
+/// a single floating-point approximation: every operation rounds its
+/// lower bound down and its upper bound up after the exact endpoint
+/// computation, so the true mathematical result is always contained in
+/// `[lo, hi]` even after repeated operations accumulate rounding error.
+///
+/// # Examples
+/// ```python
+/// import digits_calculator
+/// a = digits_calculator.Interval(1.0, 2.0)
+/// b = digits_calculator.Interval(3.0, 4.0)
+/// c = a.add(b)
+/// c.contains(5.5)  # True
+/// ```
+#[pyclass]
+#[derive(Clone, Copy)]
+struct Interval {
+    #[pyo3(get)]
+    lo: f64,
+    #[pyo3(get)]
+    hi: f64,
+}
+
+#[pymethods]
+impl Interval {
+    /// Creates an interval from explicit bounds (`lo` must be `<= hi`).
+    #[new]
+    fn new(lo: f64, hi: f64) -> Self {
+        Interval { lo, hi }
+    }
+
+    /// Lifts a scalar into a zero-width interval.
+    #[staticmethod]
+    fn from_scalar(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    fn add(&self, other: &Interval) -> Interval {
+        Interval {
+            lo: (self.lo + other.lo).next_down(),
+            hi: (self.hi + other.hi).next_up(),
+        }
+    }
+
+    fn sub(&self, other: &Interval) -> Interval {
+        Interval {
+            lo: (self.lo - other.hi).next_down(),
+            hi: (self.hi - other.lo).next_up(),
+        }
+    }
+
+    fn mul(&self, other: &Interval) -> Interval {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    /// Square root of the interval. `lo` is clamped to 0 since negative
+    /// bounds have no real square root within this enclosure.
+    fn sqrt(&self) -> Interval {
+        let lo = self.lo.max(0.0);
+        Interval {
+            lo: lo.sqrt().next_down(),
+            hi: self.hi.sqrt().next_up(),
+        }
+    }
+
+    /// Returns whether `x` lies within `[lo, hi]`.
+    fn contains(&self, x: f64) -> bool {
+        x >= self.lo && x <= self.hi
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Interval(lo={}, hi={})", self.lo, self.hi)
+    }
+}
+
 /// Python module for high-performance mathematical calculations.
 /// Exposes Rust functions optimized for speed and precision.
 #[pymodule]
@@ -178,8 +792,18 @@ fn digits_calculator(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(calculate_pi))?;
     m.add_wrapped(wrap_pyfunction!(sum_as_string))?;
     m.add_wrapped(wrap_pyfunction!(divide))?;
+    m.add_wrapped(wrap_pyfunction!(divide_ieee))?;
     m.add_wrapped(wrap_pyfunction!(safe_sqrt))?;
+    m.add_wrapped(wrap_pyfunction!(sqrt_ieee))?;
     m.add_wrapped(wrap_pyfunction!(factorial))?;
+    m.add_wrapped(wrap_pyfunction!(factorial_big))?;
+    m.add_wrapped(wrap_pyfunction!(gamma))?;
+    m.add_wrapped(wrap_pyfunction!(factorial_real))?;
+    m.add_wrapped(wrap_pyfunction!(double_factorial))?;
+    m.add_wrapped(wrap_pyfunction!(fibonacci))?;
+    m.add_wrapped(wrap_pyfunction!(fibonacci_big))?;
+    m.add_wrapped(wrap_pyfunction!(matrix_multiply))?;
+    m.add_class::<Interval>()?;
     Ok(())
 }
 
@@ -289,6 +913,48 @@ mod tests {
         assert_eq!(result, 0.0, "sqrt(0) should be 0");
     }
 
+    #[test]
+    fn test_divide_ieee_by_positive_zero() {
+        let result = divide_ieee(1.0, 0.0).unwrap();
+        assert_eq!(result, f64::INFINITY, "1.0 / 0.0 should be +inf");
+    }
+
+    #[test]
+    fn test_divide_ieee_by_negative_zero() {
+        let result = divide_ieee(1.0, -0.0).unwrap();
+        assert_eq!(result, f64::NEG_INFINITY, "1.0 / -0.0 should be -inf");
+    }
+
+    #[test]
+    fn test_divide_ieee_zero_by_zero() {
+        let result = divide_ieee(0.0, 0.0).unwrap();
+        assert!(result.is_nan(), "0.0 / 0.0 should be NaN");
+    }
+
+    #[test]
+    fn test_divide_ieee_basic() {
+        let result = divide_ieee(10.0, 2.0).unwrap();
+        assert!((result - 5.0).abs() < 0.0001, "10 / 2 should equal 5");
+    }
+
+    #[test]
+    fn test_sqrt_ieee_negative_is_nan() {
+        let result = sqrt_ieee(-9.0).unwrap();
+        assert!(result.is_nan(), "sqrt of negative should be NaN");
+    }
+
+    #[test]
+    fn test_sqrt_ieee_negative_zero() {
+        let result = sqrt_ieee(-0.0).unwrap();
+        assert!(result.is_sign_negative() && result == 0.0, "sqrt(-0.0) should be -0.0");
+    }
+
+    #[test]
+    fn test_sqrt_ieee_basic() {
+        let result = sqrt_ieee(16.0).unwrap();
+        assert!((result - 4.0).abs() < 0.0001, "sqrt(16) should be 4");
+    }
+
     #[test]
     fn test_factorial_basic() {
         let result = factorial(5).unwrap();
@@ -318,6 +984,185 @@ mod tests {
         let result = factorial(20).unwrap();
         assert_eq!(result, 2432902008176640000, "20! should be 2432902008176640000");
     }
+
+    #[test]
+    fn test_factorial_big_matches_u64_factorial() {
+        let result = factorial_big(20).unwrap();
+        assert_eq!(result, "2432902008176640000", "20! should match the u64 fast path");
+    }
+
+    #[test]
+    fn test_factorial_big_zero() {
+        let result = factorial_big(0).unwrap();
+        assert_eq!(result, "1", "0! should be 1");
+    }
+
+    #[test]
+    fn test_factorial_big_beyond_u64_range() {
+        let result = factorial_big(30).unwrap();
+        assert_eq!(result, "265252859812191058636308480000000", "30! overflows u64 but must stay exact");
+    }
+
+    #[test]
+    fn test_factorial_big_negative() {
+        let result = factorial_big(-5);
+        assert!(result.is_err(), "Factorial of negative should return an error");
+    }
+
+    #[test]
+    fn test_interval_add_encloses_true_sum() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(3.0, 4.0);
+        let result = a.add(&b);
+        assert!(result.lo <= 4.0 && result.hi >= 6.0, "Sum must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_mul_with_negative_bounds() {
+        let a = Interval::new(-2.0, 3.0);
+        let b = Interval::new(-1.0, 4.0);
+        let result = a.mul(&b);
+        assert!(result.lo <= -8.0 && result.hi >= 12.0, "Product must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_sqrt_of_positive() {
+        let a = Interval::new(4.0, 9.0);
+        let result = a.sqrt();
+        assert!(result.lo <= 2.0 && result.hi >= 3.0, "sqrt must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let a = Interval::new(1.0, 2.0);
+        assert!(a.contains(1.5));
+        assert!(!a.contains(2.5));
+    }
+
+    #[test]
+    fn test_gamma_matches_integer_factorial() {
+        let result = gamma(6.0).unwrap();
+        assert!((result - 120.0).abs() < 1e-9, "Gamma(6) should be 5! = 120");
+    }
+
+    #[test]
+    fn test_gamma_pole_is_nan() {
+        let result = gamma(-2.0).unwrap();
+        assert!(result.is_nan(), "Gamma has poles at non-positive integers");
+    }
+
+    #[test]
+    fn test_gamma_stays_finite_for_large_arguments() {
+        // Gamma(150) ~ 3.81e260, Gamma(170) ~ 4.27e304: both well inside
+        // f64 range, but evaluating t.powf(x-0.5) and (-t).exp() as
+        // separate factors overflows to `inf` long before the true
+        // product does.
+        for x in [150.0, 160.0, 170.0] {
+            let result = gamma(x).unwrap();
+            assert!(result.is_finite(), "Gamma({}) should be finite, got {}", x, result);
+        }
+    }
+
+    #[test]
+    fn test_factorial_real_matches_u64_factorial() {
+        let result = factorial_real(5.0).unwrap();
+        assert!((result - 120.0).abs() < 1e-9, "factorial_real(5) should be 5! = 120");
+    }
+
+    #[test]
+    fn test_double_factorial_basic() {
+        let result = double_factorial(7).unwrap();
+        assert_eq!(result, 105, "7!! = 7*5*3*1 = 105");
+    }
+
+    #[test]
+    fn test_double_factorial_zero() {
+        let result = double_factorial(0).unwrap();
+        assert_eq!(result, 1, "0!! = 1 by convention");
+    }
+
+    #[test]
+    fn test_double_factorial_invalid() {
+        let result = double_factorial(-2);
+        assert!(result.is_err(), "Double factorial is undefined below -1");
+    }
+
+    #[test]
+    fn test_double_factorial_overflow() {
+        let result = double_factorial(50);
+        assert!(result.is_err(), "50!! overflows u64 and should error");
+    }
+
+    #[test]
+    fn test_fibonacci_basic() {
+        let result = fibonacci(10).unwrap();
+        assert_eq!(result, 55, "F(10) should be 55");
+    }
+
+    #[test]
+    fn test_fibonacci_at_u128_ceiling() {
+        let result = fibonacci(186).unwrap();
+        assert_eq!(
+            result,
+            332_825_110_087_067_562_321_196_029_789_634_457_848,
+            "F(186) must still fit in u128"
+        );
+    }
+
+    #[test]
+    fn test_fibonacci_beyond_u128_ceiling_errors() {
+        let result = fibonacci(187);
+        assert!(result.is_err(), "F(187) overflows u128");
+    }
+
+    #[test]
+    fn test_fibonacci_big_matches_fibonacci() {
+        let result = fibonacci_big(10).unwrap();
+        assert_eq!(result, "55", "fibonacci_big(10) should match fibonacci(10)");
+    }
+
+    #[test]
+    fn test_fibonacci_big_beyond_u128_ceiling() {
+        let result = fibonacci_big(200).unwrap();
+        assert_eq!(result, "280571172992510140037611932413038677189525");
+    }
+
+    #[test]
+    fn test_matrix_multiply_integers_stay_exact() {
+        let result = matrix_multiply(
+            MatrixArg::Ints(vec![vec![1, 2], vec![3, 4]]),
+            MatrixArg::Ints(vec![vec![5, 6], vec![7, 8]]),
+        )
+        .unwrap();
+
+        match result {
+            MatrixResult::Ints(m) => assert_eq!(m, vec![vec![19, 22], vec![43, 50]]),
+            MatrixResult::Floats(_) => panic!("int * int should stay integer"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_multiply_mixed_promotes_to_float() {
+        let result = matrix_multiply(
+            MatrixArg::Ints(vec![vec![1, 2]]),
+            MatrixArg::Floats(vec![vec![3.0], vec![4.0]]),
+        )
+        .unwrap();
+
+        match result {
+            MatrixResult::Floats(m) => assert_eq!(m, vec![vec![11.0]]),
+            MatrixResult::Ints(_) => panic!("mixed input should promote to float"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_multiply_dimension_mismatch_errors() {
+        let result = matrix_multiply(
+            MatrixArg::Ints(vec![vec![1, 2]]),
+            MatrixArg::Ints(vec![vec![1, 2]]),
+        );
+        assert!(result.is_err());
+    }
 }
 
 
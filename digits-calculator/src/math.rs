@@ -1,6 +1,8 @@
 //! Pure Rust math functions - no PyO3 dependencies
 //! These can be freely tested with `cargo test`
 
+use std::ops::{Add, Mul, Sub};
+
 /// Calculates an approximation of Pi using the Leibniz formula.
 ///
 /// The Leibniz formula states that Ï€/4 = 1 - 1/3 + 1/5 - 1/7 + ...
@@ -12,18 +14,97 @@ pub fn calculate_pi(iterations: u32) -> f64 {
     pi
 }
 
-/// Multiplies two matrices.
+/// Computes `sum(x*y for x, y in zip(xs, ys))` with compensated summation.
+///
+/// Mirrors the `sumprod` added to CPython's `math` module: naive `+=`
+/// accumulation loses precision on large or mixed-magnitude inputs, so
+/// this uses Neumaier (improved Kahan) compensated summation to keep the
+/// running error bounded regardless of input order or magnitude.
+///
+/// # Arguments
+/// * `xs` - First sequence of values
+/// * `ys` - Second sequence of values, same length as `xs`
+///
+/// # Returns
+/// * `Ok(f64)` - The compensated dot product
+/// * `Err(String)` - If `xs` and `ys` have different lengths
+pub fn sumprod(xs: &[f64], ys: &[f64]) -> Result<f64, String> {
+    if xs.len() != ys.len() {
+        return Err(format!(
+            "xs and ys must have the same length: {} != {}",
+            xs.len(),
+            ys.len()
+        ));
+    }
+
+    let mut sum = 0.0;
+    let mut c = 0.0;
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        let p = x * y;
+        let t = sum + p;
+        if sum.abs() >= p.abs() {
+            c += (sum - t) + p;
+        } else {
+            c += (p - t) + sum;
+        }
+        sum = t;
+    }
+
+    Ok(sum + c)
+}
+
+/// A type with an additive identity, mirroring `num_traits::Zero`.
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+impl Zero for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+impl Zero for i64 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+/// Computes the dot product of two equal-length slices.
+///
+/// The default implementation accumulates naively; [`f64`] overrides it
+/// to reuse [`sumprod`]'s compensated summation, since that's the only
+/// element type where rounding error actually accumulates.
+pub trait DotProduct: Copy + Add<Output = Self> + Mul<Output = Self> + Zero {
+    fn dot(xs: &[Self], ys: &[Self]) -> Self {
+        xs.iter()
+            .zip(ys.iter())
+            .fold(Self::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+}
+
+impl DotProduct for i64 {}
+
+impl DotProduct for f64 {
+    fn dot(xs: &[Self], ys: &[Self]) -> Self {
+        // Lengths are validated equal by matrix_multiply before this is
+        // ever called, so sumprod cannot fail here.
+        sumprod(xs, ys).expect("xs and ys must have equal length")
+    }
+}
+
+/// Multiplies two matrices of any [`DotProduct`] element type.
 ///
 /// Performs standard matrix multiplication where the result matrix dimensions
 /// are (rows of A) x (cols of B). The number of columns in A must equal
 /// the number of rows in B.
 ///
 /// # Arguments
-/// * `a` - First matrix as Vec<Vec<f64>>
-/// * `b` - Second matrix as Vec<Vec<f64>>
+/// * `a` - First matrix
+/// * `b` - Second matrix
 ///
 /// # Returns
-/// * `Ok(Vec<Vec<f64>>)` - The resulting matrix
+/// * `Ok(Vec<Vec<T>>)` - The resulting matrix
 /// * `Err(String)` - Error message if dimensions are incompatible
 ///
 /// # Examples
@@ -33,10 +114,10 @@ pub fn calculate_pi(iterations: u32) -> f64 {
 /// let result = matrix_multiply(&a, &b).unwrap();
 /// // result = [[19.0, 22.0], [43.0, 50.0]]
 /// ```
-pub fn matrix_multiply(
-    a: &[Vec<f64>],
-    b: &[Vec<f64>],
-) -> Result<Vec<Vec<f64>>, String> {
+pub fn matrix_multiply<T: DotProduct>(
+    a: &[Vec<T>],
+    b: &[Vec<T>],
+) -> Result<Vec<Vec<T>>, String> {
     // Validate input matrices are not empty
     if a.is_empty() || b.is_empty() {
         return Err("Matrices cannot be empty".to_string());
@@ -73,15 +154,13 @@ pub fn matrix_multiply(
     }
 
     // Perform matrix multiplication
-    let mut result = vec![vec![0.0; cols_b]; rows_a];
+    let mut result = vec![vec![T::zero(); cols_b]; rows_a];
 
     for i in 0..rows_a {
+        let row = &a[i];
         for j in 0..cols_b {
-            let mut sum = 0.0;
-            for k in 0..cols_a {
-                sum += a[i][k] * b[k][j];
-            }
-            result[i][j] = sum;
+            let col: Vec<T> = (0..cols_a).map(|k| b[k][j]).collect();
+            result[i][j] = T::dot(row, &col);
         }
     }
 
@@ -106,6 +185,23 @@ pub fn safe_sqrt(x: f64) -> Result<f64, String> {
     }
 }
 
+/// Divides two numbers following IEEE-754 semantics instead of erroring.
+///
+/// Unlike [`divide`], this never returns `Err`: division by zero yields
+/// signed infinity and `0.0 / 0.0` yields `NaN`, matching the rules
+/// CPython's `math` module documents for IEEE 754 floating point.
+pub fn divide_ieee(a: f64, b: f64) -> f64 {
+    a / b
+}
+
+/// Calculates the square root following IEEE-754 semantics instead of erroring.
+///
+/// Unlike [`safe_sqrt`], this never returns `Err`: the square root of a
+/// negative number is `NaN`, and `sqrt(-0.0)` is `-0.0`.
+pub fn sqrt_ieee(x: f64) -> f64 {
+    x.sqrt()
+}
+
 /// Calculates factorial.
 pub fn factorial(n: i32) -> Result<u64, String> {
     if n < 0 {
@@ -131,6 +227,293 @@ pub fn sum_as_string(a: i64, b: i64) -> String {
     (a + b).to_string()
 }
 
+/// Lanczos approximation coefficients for g = 7, as used by most
+/// reference implementations of the gamma function.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Evaluates the gamma function using the Lanczos approximation.
+///
+/// For `x < 0.5` this applies the reflection formula
+/// `Γ(x) = π / (sin(πx)·Γ(1−x))` so the approximation only needs to cover
+/// the right half-plane. The poles at non-positive integers (where
+/// `sin(πx) == 0`) return `NaN`.
+pub fn gamma(x: f64) -> f64 {
+    if x <= 0.0 && x.fract() == 0.0 {
+        return f64::NAN;
+    }
+
+    if x < 0.5 {
+        let sin_term = (std::f64::consts::PI * x).sin();
+        return std::f64::consts::PI / (sin_term * gamma(1.0 - x));
+    }
+
+    let mut a = LANCZOS_COEFFICIENTS[0];
+    for (i, c) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        a += c / (x - 1.0 + i as f64);
+    }
+    let t = x - 1.0 + LANCZOS_G + 0.5;
+    // Evaluate in log-space: `t.powf(x - 0.5)` and `(-t).exp()` each
+    // individually overflow/underflow well before their true product
+    // does, which made gamma() return `inf` as early as x ~ 142 even
+    // though the true value (e.g. Gamma(170) ~ 4.27e304) fits in f64.
+    let ln_result = 0.5 * (2.0 * std::f64::consts::PI).ln() + (x - 0.5) * t.ln() - t + a.ln();
+    ln_result.exp()
+}
+
+/// Generalized factorial for real arguments, via `Γ(x + 1)`.
+///
+/// Extends [`factorial`] to fractional and large arguments, returning
+/// `inf` on overflow per IEEE 754 rather than erroring.
+pub fn factorial_real(x: f64) -> f64 {
+    gamma(x + 1.0)
+}
+
+/// Computes the double factorial `n!! = n·(n-2)·(n-4)·…`.
+///
+/// By convention `0!! == 1` and `(-1)!! == 1`.
+pub fn double_factorial(n: i64) -> Result<u64, String> {
+    if n < -1 {
+        return Err("Double factorial is not defined for n < -1".to_string());
+    }
+    if n <= 0 {
+        return Ok(1);
+    }
+
+    let mut result: u64 = 1;
+    let mut i = n;
+    while i > 0 {
+        result = result
+            .checked_mul(i as u64)
+            .ok_or_else(|| "Double factorial result is too large".to_string())?;
+        i -= 2;
+    }
+
+    Ok(result)
+}
+
+/// A minimal arbitrary-precision unsigned integer.
+///
+/// Stores the value as little-endian limbs in base 1_000_000_000, which
+/// keeps formatting trivial (each limb but the last is zero-padded to
+/// 9 digits) while avoiding per-digit carry arithmetic.
+#[derive(Clone)]
+struct BigUint {
+    /// Little-endian base-1_000_000_000 limbs.
+    limbs: Vec<u32>,
+}
+
+const BIG_UINT_BASE: u64 = 1_000_000_000;
+
+impl BigUint {
+    fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    /// Multiplies `self` in place by a small scalar `k`.
+    fn mul_small(&mut self, k: u64) {
+        let mut carry: u64 = 0;
+        for limb in self.limbs.iter_mut() {
+            let cur = *limb as u64 * k + carry;
+            *limb = (cur % BIG_UINT_BASE) as u32;
+            carry = cur / BIG_UINT_BASE;
+        }
+        while carry > 0 {
+            self.limbs.push((carry % BIG_UINT_BASE) as u32);
+            carry /= BIG_UINT_BASE;
+        }
+    }
+
+    /// Formats the value as a plain decimal string.
+    fn to_decimal_string(&self) -> String {
+        let mut limbs = self.limbs.iter().rev();
+        let mut s = limbs.next().map_or("0".to_string(), |top| top.to_string());
+        for limb in limbs {
+            s.push_str(&format!("{:09}", limb));
+        }
+        s
+    }
+}
+
+/// Calculates an exact factorial of arbitrary size as a decimal string.
+///
+/// Unlike [`factorial`], which saturates past `20!`, this builds the
+/// exact result using a base-1_000_000_000 big integer, so it stays
+/// correct no matter how large `n` is.
+pub fn factorial_big(n: i32) -> Result<String, String> {
+    if n < 0 {
+        return Err("Factorial is not defined for negative numbers".to_string());
+    }
+
+    let mut acc = BigUint::one();
+    for i in 2..=(n as u64) {
+        acc.mul_small(i);
+    }
+
+    Ok(acc.to_decimal_string())
+}
+
+impl BigUint {
+    fn zero() -> Self {
+        BigUint { limbs: vec![0] }
+    }
+
+    /// Adds `other` to `self` in place.
+    fn add_assign(&mut self, other: &BigUint) {
+        let mut carry: u64 = 0;
+        for i in 0..other.limbs.len().max(self.limbs.len()) {
+            let a = *self.limbs.get(i).unwrap_or(&0) as u64;
+            let b = *other.limbs.get(i).unwrap_or(&0) as u64;
+            let cur = a + b + carry;
+            let limb = (cur % BIG_UINT_BASE) as u32;
+            carry = cur / BIG_UINT_BASE;
+            if i < self.limbs.len() {
+                self.limbs[i] = limb;
+            } else {
+                self.limbs.push(limb);
+            }
+        }
+        while carry > 0 {
+            self.limbs.push((carry % BIG_UINT_BASE) as u32);
+            carry /= BIG_UINT_BASE;
+        }
+    }
+}
+
+/// The largest `n` for which `fibonacci(n)` fits in a `u128`.
+const FIBONACCI_MAX_N: u64 = 186;
+
+/// Computes the nth Fibonacci number (`F(0) = 0`, `F(1) = 1`) iteratively.
+///
+/// Returns `Err` once the result would overflow `u128` (past `F(186)`);
+/// use [`fibonacci_big`] beyond that ceiling.
+pub fn fibonacci(n: u64) -> Result<u128, String> {
+    if n > FIBONACCI_MAX_N {
+        return Err(format!(
+            "fibonacci({}) overflows u128; use fibonacci_big instead",
+            n
+        ));
+    }
+
+    let (mut a, mut b): (u128, u128) = (0, 1);
+    for _ in 0..n {
+        // The very last `next` may be F(n+1), which can overflow u128
+        // exactly at n == FIBONACCI_MAX_N; it's discarded once the loop
+        // ends, so wrapping here is harmless.
+        let next = a.wrapping_add(b);
+        a = b;
+        b = next;
+    }
+    Ok(a)
+}
+
+/// Computes the nth Fibonacci number as an exact decimal string.
+///
+/// Uses the same base-1_000_000_000 big integer as [`factorial_big`], so
+/// there is no ceiling on `n` the way there is for [`fibonacci`].
+pub fn fibonacci_big(n: u64) -> String {
+    let (mut a, mut b) = (BigUint::zero(), BigUint::one());
+    for _ in 0..n {
+        let mut next = a.clone();
+        next.add_assign(&b);
+        a = b;
+        b = next;
+    }
+    a.to_decimal_string()
+}
+
+/// A closed interval `[lo, hi]` that rigorously encloses a real value.
+///
+/// Arithmetic on `Interval` propagates a guaranteed enclosure rather than
+/// a single floating-point approximation: every operation rounds its
+/// lower bound down (via [`f64::next_down`]) and its upper bound up (via
+/// [`f64::next_up`]) after the exact endpoint computation, so the true
+/// mathematical result is always contained in `[lo, hi]` even after
+/// repeated operations accumulate rounding error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl Interval {
+    /// Creates an interval from explicit bounds (`lo` must be `<= hi`).
+    pub fn new(lo: f64, hi: f64) -> Self {
+        Interval { lo, hi }
+    }
+
+    /// Lifts a scalar into a zero-width interval.
+    pub fn from_scalar(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    /// Square root of the interval. `lo` is clamped to 0 since negative
+    /// bounds have no real square root within this enclosure.
+    pub fn sqrt(self) -> Interval {
+        let lo = self.lo.max(0.0);
+        Interval {
+            lo: lo.sqrt().next_down(),
+            hi: self.hi.sqrt().next_up(),
+        }
+    }
+
+    /// Returns whether `x` lies within `[lo, hi]`.
+    pub fn contains(self, x: f64) -> bool {
+        x >= self.lo && x <= self.hi
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        Interval {
+            lo: (self.lo + other.lo).next_down(),
+            hi: (self.hi + other.hi).next_up(),
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        Interval {
+            lo: (self.lo - other.hi).next_down(),
+            hi: (self.hi - other.lo).next_up(),
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    fn mul(self, other: Interval) -> Interval {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,6 +554,35 @@ mod tests {
         assert!(error < 0.001, "1M iterations should be accurate within 0.001");
     }
 
+    // sumprod tests
+    #[test]
+    fn test_sumprod_basic() {
+        let result = sumprod(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(result, 32.0, "1*4 + 2*5 + 3*6 should be 32");
+    }
+
+    #[test]
+    fn test_sumprod_length_mismatch() {
+        let result = sumprod(&[1.0, 2.0], &[1.0]);
+        assert!(result.is_err(), "Mismatched lengths should return an error");
+    }
+
+    #[test]
+    fn test_sumprod_empty() {
+        let result = sumprod(&[], &[]).unwrap();
+        assert_eq!(result, 0.0, "Empty inputs should sum to 0");
+    }
+
+    #[test]
+    fn test_sumprod_more_accurate_than_naive_sum() {
+        // A case designed to lose precision under naive += accumulation:
+        // each 1.0 is absorbed into 1e16, so naive += returns 0.0, not 2.0.
+        let xs = vec![1.0, 1e16, 1.0, -1e16];
+        let ys = vec![1.0, 1.0, 1.0, 1.0];
+        let result = sumprod(&xs, &ys).unwrap();
+        assert_eq!(result, 2.0, "Compensated summation should recover both 1.0 terms");
+    }
+
     // matrix_multiply tests
     #[test]
     fn test_matrix_multiply_basic_2x2() {
@@ -278,6 +690,15 @@ mod tests {
         assert_eq!(result, vec![vec![0.0, 0.0], vec![0.0, 0.0]]);
     }
 
+    #[test]
+    fn test_matrix_multiply_integer_matrices() {
+        let a: Vec<Vec<i64>> = vec![vec![1, 2], vec![3, 4]];
+        let b: Vec<Vec<i64>> = vec![vec![5, 6], vec![7, 8]];
+        let result = matrix_multiply(&a, &b).unwrap();
+
+        assert_eq!(result, vec![vec![19, 22], vec![43, 50]], "Integer matrices should multiply exactly");
+    }
+
     #[test]
     fn test_matrix_multiply_floating_point_precision() {
         let a = vec![vec![0.1, 0.2], vec![0.3, 0.4]];
@@ -337,6 +758,38 @@ mod tests {
         assert_eq!(result.unwrap(), 0.0, "sqrt(0) should be 0");
     }
 
+    // divide_ieee tests
+    #[test]
+    fn test_divide_ieee_by_positive_zero() {
+        let result = divide_ieee(1.0, 0.0);
+        assert_eq!(result, f64::INFINITY, "1.0 / 0.0 should be +inf");
+    }
+
+    #[test]
+    fn test_divide_ieee_by_negative_zero() {
+        let result = divide_ieee(1.0, -0.0);
+        assert_eq!(result, f64::NEG_INFINITY, "1.0 / -0.0 should be -inf");
+    }
+
+    #[test]
+    fn test_divide_ieee_zero_by_zero() {
+        let result = divide_ieee(0.0, 0.0);
+        assert!(result.is_nan(), "0.0 / 0.0 should be NaN");
+    }
+
+    // sqrt_ieee tests
+    #[test]
+    fn test_sqrt_ieee_negative_is_nan() {
+        let result = sqrt_ieee(-9.0);
+        assert!(result.is_nan(), "sqrt of negative should be NaN");
+    }
+
+    #[test]
+    fn test_sqrt_ieee_negative_zero() {
+        let result = sqrt_ieee(-0.0);
+        assert!(result.is_sign_negative() && result == 0.0, "sqrt(-0.0) should be -0.0");
+    }
+
     // factorial tests
     #[test]
     fn test_factorial_basic() {
@@ -396,4 +849,191 @@ mod tests {
         let result = sum_as_string(1_000_000, 2_000_000);
         assert_eq!(result, "3000000", "1000000 + 2000000 should be '3000000'");
     }
+
+    // Interval tests
+    #[test]
+    fn test_interval_add_encloses_true_sum() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(3.0, 4.0);
+        let result = a + b;
+        assert!(result.lo <= 4.0 && result.hi >= 6.0, "Sum must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_sub_encloses_true_difference() {
+        let a = Interval::new(5.0, 6.0);
+        let b = Interval::new(1.0, 2.0);
+        let result = a - b;
+        assert!(result.lo <= 3.0 && result.hi >= 5.0, "Difference must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_mul_with_negative_bounds() {
+        let a = Interval::new(-2.0, 3.0);
+        let b = Interval::new(-1.0, 4.0);
+        let result = a * b;
+        // Candidate products: 2, -8, -3, 12 -> true range is [-8, 12]
+        assert!(result.lo <= -8.0 && result.hi >= 12.0, "Product must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_sqrt_of_positive() {
+        let a = Interval::new(4.0, 9.0);
+        let result = a.sqrt();
+        assert!(result.lo <= 2.0 && result.hi >= 3.0, "sqrt must be enclosed");
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let a = Interval::new(1.0, 2.0);
+        assert!(a.contains(1.5));
+        assert!(!a.contains(2.5));
+    }
+
+    #[test]
+    fn test_interval_from_scalar_is_zero_width() {
+        let a = Interval::from_scalar(5.0);
+        assert_eq!(a.lo, 5.0);
+        assert_eq!(a.hi, 5.0);
+    }
+
+    // factorial_big tests
+    #[test]
+    fn test_factorial_big_matches_u64_factorial() {
+        let result = factorial_big(20).unwrap();
+        assert_eq!(result, "2432902008176640000", "20! should match the u64 fast path");
+    }
+
+    #[test]
+    fn test_factorial_big_zero() {
+        let result = factorial_big(0).unwrap();
+        assert_eq!(result, "1", "0! should be 1");
+    }
+
+    #[test]
+    fn test_factorial_big_beyond_u64_range() {
+        let result = factorial_big(30).unwrap();
+        assert_eq!(result, "265252859812191058636308480000000", "30! overflows u64 but must stay exact");
+    }
+
+    #[test]
+    fn test_factorial_big_negative() {
+        let result = factorial_big(-5);
+        assert!(result.is_err(), "Factorial of negative should return an error");
+    }
+
+    // gamma / factorial_real / double_factorial tests
+    #[test]
+    fn test_gamma_matches_integer_factorial() {
+        let result = gamma(6.0);
+        assert!((result - 120.0).abs() < 1e-9, "Gamma(6) should be 5! = 120");
+    }
+
+    #[test]
+    fn test_gamma_one_half() {
+        let result = gamma(0.5);
+        assert!((result - std::f64::consts::PI.sqrt()).abs() < 1e-9, "Gamma(0.5) should be sqrt(pi)");
+    }
+
+    #[test]
+    fn test_gamma_pole_is_nan() {
+        let result = gamma(-2.0);
+        assert!(result.is_nan(), "Gamma has poles at non-positive integers");
+    }
+
+    #[test]
+    fn test_gamma_stays_finite_for_large_arguments() {
+        // Gamma(150) ~ 3.81e260, Gamma(170) ~ 4.27e304: both well inside
+        // f64 range, but evaluating t.powf(x-0.5) and (-t).exp() as
+        // separate factors overflows to `inf` long before the true
+        // product does.
+        for x in [150.0, 160.0, 170.0] {
+            let result = gamma(x);
+            assert!(result.is_finite(), "Gamma({}) should be finite, got {}", x, result);
+        }
+    }
+
+    #[test]
+    fn test_factorial_real_matches_u64_factorial() {
+        let result = factorial_real(5.0);
+        assert!((result - 120.0).abs() < 1e-9, "factorial_real(5) should be 5! = 120");
+    }
+
+    #[test]
+    fn test_factorial_real_fractional() {
+        let result = factorial_real(0.5);
+        // 0.5! = Gamma(1.5) = sqrt(pi)/2
+        assert!((result - std::f64::consts::PI.sqrt() / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_double_factorial_basic() {
+        let result = double_factorial(7).unwrap();
+        assert_eq!(result, 105, "7!! = 7*5*3*1 = 105");
+    }
+
+    #[test]
+    fn test_double_factorial_even() {
+        let result = double_factorial(8).unwrap();
+        assert_eq!(result, 384, "8!! = 8*6*4*2 = 384");
+    }
+
+    #[test]
+    fn test_double_factorial_zero_and_negative_one() {
+        assert_eq!(double_factorial(0).unwrap(), 1, "0!! = 1 by convention");
+        assert_eq!(double_factorial(-1).unwrap(), 1, "(-1)!! = 1 by convention");
+    }
+
+    #[test]
+    fn test_double_factorial_invalid() {
+        let result = double_factorial(-2);
+        assert!(result.is_err(), "Double factorial is undefined below -1");
+    }
+
+    #[test]
+    fn test_double_factorial_overflow() {
+        let result = double_factorial(50);
+        assert!(result.is_err(), "50!! overflows u64 and should error");
+    }
+
+    // fibonacci tests
+    #[test]
+    fn test_fibonacci_base_cases() {
+        assert_eq!(fibonacci(0).unwrap(), 0);
+        assert_eq!(fibonacci(1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_fibonacci_basic() {
+        assert_eq!(fibonacci(10).unwrap(), 55, "F(10) should be 55");
+    }
+
+    #[test]
+    fn test_fibonacci_at_u128_ceiling() {
+        let result = fibonacci(186);
+        assert_eq!(
+            result.unwrap(),
+            332_825_110_087_067_562_321_196_029_789_634_457_848,
+            "F(186) must still fit in u128"
+        );
+    }
+
+    #[test]
+    fn test_fibonacci_beyond_u128_ceiling_errors() {
+        let result = fibonacci(187);
+        assert!(result.is_err(), "F(187) overflows u128");
+    }
+
+    #[test]
+    fn test_fibonacci_big_matches_fibonacci() {
+        let result = fibonacci_big(10);
+        assert_eq!(result, "55", "fibonacci_big(10) should match fibonacci(10)");
+    }
+
+    #[test]
+    fn test_fibonacci_big_beyond_u128_ceiling() {
+        let result = fibonacci_big(200);
+        // F(200) = 280571172992510140037611932413038677189525
+        assert_eq!(result, "280571172992510140037611932413038677189525");
+    }
 }